@@ -0,0 +1,9 @@
+mod use_async;
+mod use_fetch;
+mod use_query;
+mod use_suspended_future;
+
+pub use use_async::*;
+pub use use_fetch::*;
+pub use use_query::*;
+pub use use_suspended_future::*;
@@ -0,0 +1,218 @@
+use std::future::Future;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use wasm_bindgen_futures::spawn_local;
+
+use crate::functional::{hook, use_effect_with_deps, use_mut_ref, use_state, UseStateHandle};
+
+/// State of the asynchronous operation driven by [`use_async`].
+///
+/// This is what a [`UseAsyncHandle`] dereferences to, so `handle.data`,
+/// `handle.error` and `handle.loading` read straight through.
+pub struct UseAsyncState<T, E> {
+    /// The value produced by the latest run, if it completed successfully.
+    /// Cleared when a new run starts.
+    pub data: Option<T>,
+    /// The error produced by the latest run, if it failed. Cleared when a new
+    /// run starts.
+    pub error: Option<E>,
+    /// `true` while a run is in flight.
+    pub loading: bool,
+}
+
+/// Handle returned by [`use_async`].
+///
+/// Besides the [`UseAsyncState`] fields reached through [`Deref`], it exposes
+/// [`run`](UseAsyncHandle::run) to (re)trigger the future.
+pub struct UseAsyncHandle<T, E> {
+    state: UseStateHandle<UseAsyncState<T, E>>,
+    run: Rc<dyn Fn()>,
+}
+
+impl<T, E> UseAsyncHandle<T, E> {
+    /// Spawn the future again, discarding the result of any run still in flight.
+    pub fn run(&self) {
+        (self.run)();
+    }
+}
+
+impl<T, E> Deref for UseAsyncHandle<T, E> {
+    type Target = UseAsyncState<T, E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl<T, E> Clone for UseAsyncHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            run: self.run.clone(),
+        }
+    }
+}
+
+/// A hook that owns an asynchronous operation and mirrors its progress into
+/// state.
+///
+/// It replaces the `use_state` + [`spawn_local`](wasm_bindgen_futures::spawn_local)
+/// + manual loading/error flag boilerplate that every fetch-from-a-component
+/// example ends up writing. The future is not started automatically; call
+/// [`run`](UseAsyncHandle::run) to fire it (e.g. from an event handler or an
+/// effect).
+///
+/// Results from a run are discarded if a newer `run()` was issued in the
+/// meantime, and the in-flight future is abandoned when the component unmounts
+/// so it never writes into a dropped scope.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// #[function_component(Fetcher)]
+/// fn fetcher() -> Html {
+///     let state = use_async(|| async { fetch_user().await });
+///
+///     let onclick = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.run())
+///     };
+///
+///     html! {
+///         <div>
+///             <button {onclick}>{ "load" }</button>
+///             if state.loading {
+///                 { "loading…" }
+///             } else if let Some(user) = &state.data {
+///                 { user }
+///             } else if let Some(err) = &state.error {
+///                 { err }
+///             }
+///         </div>
+///     }
+/// }
+/// # async fn fetch_user() -> Result<String, String> { Ok(String::new()) }
+/// ```
+#[hook]
+pub fn use_async<F, Fut, T, E>(future_factory: F) -> UseAsyncHandle<T, E>
+where
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: 'static,
+    E: 'static,
+{
+    let state = use_state(|| UseAsyncState {
+        data: None,
+        error: None,
+        loading: false,
+    });
+    // Identifies the latest run so stale futures can bow out.
+    let generation = use_mut_ref(|| 0u32);
+    // Cleared on unmount so no future writes into a dropped scope.
+    let mounted = use_mut_ref(|| true);
+
+    use_effect_with_deps(
+        {
+            let mounted = mounted.clone();
+            move |_: &()| {
+                move || {
+                    *mounted.borrow_mut() = false;
+                }
+            }
+        },
+        (),
+    );
+
+    let run = {
+        let state = state.clone();
+        let generation = generation.clone();
+        let mounted = mounted.clone();
+        let future_factory = Rc::new(future_factory);
+
+        Rc::new(move || {
+            let this_gen = {
+                let mut gen = generation.borrow_mut();
+                *gen += 1;
+                *gen
+            };
+
+            state.set(UseAsyncState {
+                data: None,
+                error: None,
+                loading: true,
+            });
+
+            let fut = future_factory();
+            let state = state.clone();
+            let generation = generation.clone();
+            let mounted = mounted.clone();
+
+            spawn_local(async move {
+                let result = fut.await;
+
+                // Ignore the result of a stale run or of one that completed
+                // after the component went away.
+                if !*mounted.borrow() || *generation.borrow() != this_gen {
+                    return;
+                }
+
+                state.set(match result {
+                    Ok(data) => UseAsyncState {
+                        data: Some(data),
+                        error: None,
+                        loading: false,
+                    },
+                    Err(error) => UseAsyncState {
+                        data: None,
+                        error: Some(error),
+                        loading: false,
+                    },
+                });
+            });
+        }) as Rc<dyn Fn()>
+    };
+
+    UseAsyncHandle { state, run }
+}
+
+/// A [`use_async`] variant that re-runs the future whenever `deps` change.
+///
+/// It mirrors the `(closure, deps)` shape of [`use_memo`](crate::functional::use_memo):
+/// `future_factory` receives the current dependencies and the future is run on
+/// mount and again every time `deps` compare unequal (via [`PartialEq`]). This
+/// is the tool for refetching when, say, an `id` prop changes, without wiring up
+/// an explicit effect.
+#[hook]
+pub fn use_async_with_deps<F, Fut, T, E, Deps>(
+    future_factory: F,
+    deps: Deps,
+) -> UseAsyncHandle<T, E>
+where
+    F: Fn(&Deps) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: 'static,
+    E: 'static,
+    Deps: PartialEq + 'static,
+{
+    let deps = Rc::new(deps);
+
+    let handle = use_async({
+        let deps = deps.clone();
+        move || future_factory(&deps)
+    });
+
+    use_effect_with_deps(
+        {
+            let handle = handle.clone();
+            move |_| {
+                handle.run();
+                || {}
+            }
+        },
+        deps,
+    );
+
+    handle
+}
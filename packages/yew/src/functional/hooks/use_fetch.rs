@@ -0,0 +1,190 @@
+use std::future::Future;
+use std::rc::Rc;
+
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{AbortController, AbortSignal};
+
+use crate::functional::{hook, use_effect_with_deps, use_memo, use_mut_ref, use_state};
+
+/// State tracked by [`use_fetch`] for the most recent, non-aborted request.
+struct UseFetchState<T, E> {
+    data: Option<T>,
+    error: Option<E>,
+    loading: bool,
+}
+
+/// Handle returned by [`use_fetch`].
+///
+/// Besides the request state it carries the [`AbortSignal`] of the current
+/// request, which callers pass to `gloo_net`/`reqwest` so the browser aborts
+/// the underlying `fetch` when the hook re-runs or the component unmounts.
+pub struct UseFetchHandle<T, E> {
+    data: Option<T>,
+    error: Option<E>,
+    loading: bool,
+    signal: AbortSignal,
+}
+
+impl<T, E> UseFetchHandle<T, E> {
+    /// The data of the latest completed request, if it succeeded. It is kept
+    /// while a newer request is loading and cleared if a request fails.
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// The error of the latest completed request, if it failed. Cleared when a
+    /// new request starts.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// `true` while a request is in flight.
+    pub fn loading(&self) -> bool {
+        self.loading
+    }
+
+    /// The [`AbortSignal`] of the current request, to hand to the HTTP client.
+    pub fn signal(&self) -> AbortSignal {
+        self.signal.clone()
+    }
+}
+
+/// A fetch hook that cancels stale in-flight requests through an
+/// [`AbortController`].
+///
+/// Each run (on mount and whenever `deps` change) gets a fresh
+/// [`AbortController`]; its [`AbortSignal`] is passed to `future_factory` and
+/// exposed on the handle so it can be forwarded to `gloo_net`/`reqwest`. When
+/// the hook re-runs or the component unmounts, the previous controller is
+/// aborted. The resulting promise rejection is treated as a cancellation, not a
+/// user-facing error.
+///
+/// Only the result of the most recent, non-aborted request is ever written into
+/// state, so a slow earlier request can never overwrite a fast later one.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use web_sys::AbortSignal;
+/// #[function_component(Search)]
+/// fn search(props: &SearchProps) -> Html {
+///     let query = props.query.clone();
+///     let result = use_fetch(
+///         move |query, signal| fetch_results(query.clone(), signal),
+///         query,
+///     );
+///
+///     html! {
+///         if result.loading() {
+///             { "searching…" }
+///         } else if let Some(items) = result.data() {
+///             { items }
+///         }
+///     }
+/// }
+/// # #[derive(Properties, PartialEq)] struct SearchProps { query: String }
+/// # async fn fetch_results(_: String, _: AbortSignal) -> Result<String, String> { Ok(String::new()) }
+/// ```
+#[hook]
+pub fn use_fetch<F, Fut, T, E, Deps>(future_factory: F, deps: Deps) -> UseFetchHandle<T, E>
+where
+    F: Fn(&Deps, AbortSignal) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: Clone + 'static,
+    E: Clone + 'static,
+    Deps: PartialEq + 'static,
+{
+    let state = use_state(|| UseFetchState {
+        data: None,
+        error: None,
+        loading: false,
+    });
+    // Identifies the latest run so aborted/stale requests never write state.
+    let generation = use_mut_ref(|| 0u32);
+    // Cleared on unmount so no request writes into a dropped scope.
+    let mounted = use_mut_ref(|| true);
+    let deps = Rc::new(deps);
+
+    use_effect_with_deps(
+        {
+            let mounted = mounted.clone();
+            move |_: &()| {
+                move || {
+                    *mounted.borrow_mut() = false;
+                }
+            }
+        },
+        (),
+    );
+
+    // A fresh controller per `deps`, available during render so its signal can
+    // be returned even before the effect fires.
+    let controller = use_memo(
+        |_| AbortController::new().expect("failed to create AbortController"),
+        deps.clone(),
+    );
+    let signal = controller.signal();
+
+    use_effect_with_deps(
+        {
+            let state = state.clone();
+            let generation = generation.clone();
+            let controller = controller.clone();
+            let mounted = mounted.clone();
+            let future_factory = Rc::new(future_factory);
+            move |deps: &Rc<Deps>| {
+                let this_gen = {
+                    let mut gen = generation.borrow_mut();
+                    *gen += 1;
+                    *gen
+                };
+
+                state.set(UseFetchState {
+                    data: state.data.clone(),
+                    error: None,
+                    loading: true,
+                });
+
+                let future = future_factory(deps, controller.signal());
+                {
+                    let state = state.clone();
+                    let generation = generation.clone();
+                    let mounted = mounted.clone();
+                    spawn_local(async move {
+                        let result = future.await;
+                        // Drop the result of an aborted or superseded request, or
+                        // of one that completed after the component unmounted.
+                        if !*mounted.borrow() || *generation.borrow() != this_gen {
+                            return;
+                        }
+                        state.set(match result {
+                            Ok(data) => UseFetchState {
+                                data: Some(data),
+                                error: None,
+                                loading: false,
+                            },
+                            Err(error) => UseFetchState {
+                                data: None,
+                                error: Some(error),
+                                loading: false,
+                            },
+                        });
+                    });
+                }
+
+                // On re-run (deps change) or unmount, abort the in-flight
+                // request; its rejection is ignored via the generation guard.
+                move || controller.abort()
+            }
+        },
+        deps,
+    );
+
+    UseFetchHandle {
+        data: state.data.clone(),
+        error: state.error.clone(),
+        loading: state.loading,
+        signal,
+    }
+}
@@ -0,0 +1,382 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+use wasm_bindgen_futures::spawn_local;
+
+use crate::functional::{hook, use_effect_with_deps, use_force_update, use_mut_ref};
+
+/// Status of a cached query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryStatus {
+    /// No fetch has ever completed for this key.
+    Idle,
+    /// A fetch is currently in flight.
+    Fetching,
+    /// The last fetch succeeded and data is available. While a revalidation
+    /// runs the status is `Fetching`, and the previous data stays available.
+    Success,
+    /// The last fetch failed.
+    Error,
+}
+
+/// Options controlling [`use_query_with_options`].
+#[derive(Debug, Clone, Copy)]
+pub struct QueryOptions {
+    /// How long freshly fetched data is considered fresh. While fresh, mounting
+    /// a component for the key reuses the cached value and skips revalidation.
+    pub stale_time: Duration,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        // Data stays fresh for 30s, so remounting within that window reuses the
+        // cached value instead of refetching.
+        Self {
+            stale_time: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Type-erased cache key that delegates [`Hash`]/[`Eq`] to the user's key type.
+#[derive(Clone)]
+struct Key(Rc<dyn DynKey>);
+
+trait DynKey {
+    fn dyn_eq(&self, other: &dyn DynKey) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<K: Hash + Eq + 'static> DynKey for K {
+    fn dyn_eq(&self, other: &dyn DynKey) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<K>()
+            .map_or(false, |other| self == other)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        Hash::hash(self, &mut state);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.dyn_eq(other.0.as_ref())
+    }
+}
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.dyn_hash(state);
+    }
+}
+
+/// A single cache entry, shared by every component mounted for its key.
+struct QueryEntry<T, E> {
+    data: Option<T>,
+    error: Option<E>,
+    updated_at: Option<f64>,
+    status: QueryStatus,
+    /// `true` while a fetch is in flight; used to deduplicate concurrent mounts.
+    in_flight: bool,
+    /// Notifiers for every mounted component sharing this key. Held weakly so
+    /// dropped components are cleaned up lazily.
+    subscribers: Vec<Weak<dyn Fn()>>,
+    /// Spawns a fresh fetch for this key, registered by mounted components so
+    /// [`mutate`] can force a refetch without holding a fetcher itself.
+    revalidator: Option<Rc<dyn Fn()>>,
+}
+
+impl<T, E> QueryEntry<T, E> {
+    fn new() -> Self {
+        Self {
+            data: None,
+            error: None,
+            updated_at: None,
+            status: QueryStatus::Idle,
+            in_flight: false,
+            subscribers: Vec::new(),
+            revalidator: None,
+        }
+    }
+
+    fn notify(&mut self) {
+        self.subscribers.retain(|weak| {
+            if let Some(notifier) = weak.upgrade() {
+                notifier();
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+thread_local! {
+    static QUERY_CACHE: RefCell<HashMap<Key, Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Fetch or create the shared entry for `key`.
+fn entry_for<T, E>(key: &Key) -> Rc<RefCell<QueryEntry<T, E>>>
+where
+    T: 'static,
+    E: 'static,
+{
+    QUERY_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let any = cache
+            .entry(key.clone())
+            .or_insert_with(|| Rc::new(RefCell::new(QueryEntry::<T, E>::new())));
+        any.clone()
+            .downcast::<RefCell<QueryEntry<T, E>>>()
+            .expect("cache entry type mismatch for query key")
+    })
+}
+
+/// Spawn `fetcher` for `entry` unless a fetch is already in flight.
+fn revalidate<Fut, T, E>(entry: &Rc<RefCell<QueryEntry<T, E>>>, fetcher: impl FnOnce() -> Fut)
+where
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: 'static,
+    E: 'static,
+{
+    {
+        let mut borrow = entry.borrow_mut();
+        if borrow.in_flight {
+            // Deduplicate: another component already kicked off the request.
+            return;
+        }
+        borrow.in_flight = true;
+        borrow.status = QueryStatus::Fetching;
+        borrow.notify();
+    }
+
+    let fut = fetcher();
+    let entry = entry.clone();
+    spawn_local(async move {
+        let result = fut.await;
+        let mut borrow = entry.borrow_mut();
+        borrow.in_flight = false;
+        borrow.updated_at = Some(now_ms());
+        match result {
+            Ok(data) => {
+                borrow.data = Some(data);
+                borrow.error = None;
+                borrow.status = QueryStatus::Success;
+            }
+            Err(error) => {
+                borrow.error = Some(error);
+                borrow.status = QueryStatus::Error;
+            }
+        }
+        borrow.notify();
+    });
+}
+
+/// Snapshot of a query handed to a component on each render.
+pub struct UseQueryHandle<T, E> {
+    data: Option<T>,
+    error: Option<E>,
+    status: QueryStatus,
+}
+
+impl<T, E> UseQueryHandle<T, E> {
+    /// The cached data, if any (may be stale while a revalidation runs).
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    /// The error from the last failed fetch, if any.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// The current [`QueryStatus`].
+    pub fn status(&self) -> QueryStatus {
+        self.status
+    }
+
+    /// `true` while a fetch is in flight.
+    pub fn loading(&self) -> bool {
+        self.status == QueryStatus::Fetching
+    }
+}
+
+impl<T, E> Deref for UseQueryHandle<T, E> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+/// A stale-while-revalidate data-fetching hook backed by a process-global cache.
+///
+/// On mount the hook returns cached data immediately if present, then
+/// revalidates in the background unless that data is still fresh (see
+/// [`QueryOptions::stale_time`]), pushing the fresh value to every mounted
+/// component that shares `key`. Concurrent mounts for the same key are
+/// deduplicated into a single request. Use [`mutate`] to invalidate a key and
+/// force a refetch.
+///
+/// `key` must be hashable and comparable; `fetcher` is an async closure
+/// returning `Result<T, E>`.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// #[function_component(User)]
+/// fn user(props: &UserProps) -> Html {
+///     let id = props.id;
+///     let query = use_query(id, move || async move { fetch_user(id).await });
+///
+///     html! {
+///         if let Some(name) = query.data() {
+///             { name }
+///         } else {
+///             { "loading…" }
+///         }
+///     }
+/// }
+/// # #[derive(Properties, PartialEq)] struct UserProps { id: u32 }
+/// # async fn fetch_user(_: u32) -> Result<String, String> { Ok(String::new()) }
+/// ```
+#[hook]
+pub fn use_query<K, F, Fut, T, E>(key: K, fetcher: F) -> UseQueryHandle<T, E>
+where
+    K: Hash + Eq + Clone + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    use_query_with_options(key, fetcher, QueryOptions::default())
+}
+
+/// [`use_query`] with explicit [`QueryOptions`] (e.g. a custom `stale_time`).
+#[hook]
+pub fn use_query_with_options<K, F, Fut, T, E>(
+    key: K,
+    fetcher: F,
+    options: QueryOptions,
+) -> UseQueryHandle<T, E>
+where
+    K: Hash + Eq + Clone + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    let key = Key(Rc::new(key));
+    let entry = entry_for::<T, E>(&key);
+
+    let trigger = use_force_update();
+    // Keep the notifier alive for the lifetime of the component; the cache holds
+    // only a `Weak`, so it is dropped — and cleaned up — on unmount.
+    let notifier = use_mut_ref(|| {
+        Rc::new(move || trigger.force_update()) as Rc<dyn Fn()>
+    });
+
+    // All subscription and revalidation side effects run once per key from an
+    // effect — never from the render path — so a completed fetch's `force_update`
+    // cannot trigger another fetch. On key change / unmount the subscription to
+    // the previous entry is dropped so it no longer wakes this component.
+    use_effect_with_deps(
+        {
+            let entry = entry.clone();
+            let weak = Rc::downgrade(&notifier.borrow());
+            let fetcher = Rc::new(fetcher);
+            let stale_time = options.stale_time;
+            move |_| {
+                entry.borrow_mut().subscribers.push(weak.clone());
+
+                // Register a fetcher-bound revalidator so `mutate` can refetch.
+                let revalidator = {
+                    let entry = entry.clone();
+                    let fetcher = fetcher.clone();
+                    Rc::new(move || revalidate(&entry, || fetcher())) as Rc<dyn Fn()>
+                };
+                entry.borrow_mut().revalidator = Some(revalidator.clone());
+
+                // Stale-while-revalidate: refetch on mount unless cached data is
+                // still fresh within `stale_time`.
+                let stale = match entry.borrow().updated_at {
+                    Some(updated_at) => {
+                        now_ms() - updated_at >= stale_time.as_millis() as f64
+                    }
+                    None => true,
+                };
+                if stale {
+                    revalidator();
+                }
+
+                let entry = entry.clone();
+                move || {
+                    let mut borrow = entry.borrow_mut();
+                    borrow.subscribers.retain(|sub| !sub.ptr_eq(&weak));
+                    // Once no component is mounted for this key, drop the
+                    // revalidator so `mutate` only marks the entry stale.
+                    if borrow.subscribers.iter().all(|sub| sub.strong_count() == 0) {
+                        borrow.revalidator = None;
+                    }
+                }
+            }
+        },
+        key.clone(),
+    );
+
+    let borrow = entry.borrow();
+    UseQueryHandle {
+        data: borrow.data.clone(),
+        error: borrow.error.clone(),
+        status: borrow.status,
+    }
+}
+
+/// Invalidate the cache entry for `key` and force a refetch.
+///
+/// The generic parameters pin the value and error types of the entry, which
+/// must match the [`use_query`] call that created it.
+pub fn mutate<K, T, E>(key: K)
+where
+    K: Hash + Eq + Clone + 'static,
+    T: 'static,
+    E: 'static,
+{
+    let key = Key(Rc::new(key));
+    let exists = QUERY_CACHE.with(|cache| cache.borrow().contains_key(&key));
+    if !exists {
+        return;
+    }
+
+    let entry = entry_for::<T, E>(&key);
+    // Mark stale, then kick the registered revalidator (which notifies
+    // subscribers as the fetch progresses). With no mounted component there is
+    // no revalidator; the next mount will refetch because the entry is stale.
+    let revalidator = {
+        let mut borrow = entry.borrow_mut();
+        borrow.updated_at = None;
+        borrow.revalidator.clone()
+    };
+    if let Some(revalidator) = revalidator {
+        revalidator();
+    }
+}
@@ -0,0 +1,116 @@
+use std::future::Future;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use wasm_bindgen_futures::spawn_local;
+
+use crate::functional::{hook, use_memo, use_mut_ref, use_state};
+use crate::suspense::{Suspension, SuspensionResult};
+
+/// Handle returned by [`use_suspended_future`] once its future has resolved.
+///
+/// It dereferences to the future's `Result<T, E>` output, so a rejected future
+/// surfaces as `Err` to match on rather than suspending forever.
+pub struct UseSuspendedFutureHandle<T, E> {
+    inner: Rc<Result<T, E>>,
+}
+
+impl<T, E> Deref for UseSuspendedFutureHandle<T, E> {
+    type Target = Result<T, E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, E> Clone for UseSuspendedFutureHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// A hook that suspends the component until `future_factory`'s future resolves.
+///
+/// While the future is pending the hook throws a [`Suspension`] into the nearest
+/// [`<Suspense fallback=...>`](crate::suspense::Suspense) boundary, which renders
+/// its fallback automatically — no manual `loading` flag or spinner. Once the
+/// future resolves, the hook returns the value directly.
+///
+/// The resolved value is cached keyed by `deps` (compared with [`PartialEq`]),
+/// so re-renders after resolution do not re-suspend; the future re-runs — and
+/// the component suspends again — only when `deps` change.
+///
+/// A rejected future does not suspend forever: its `Err` is returned through the
+/// handle so the component can match on it.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// #[function_component(Profile)]
+/// fn profile(props: &ProfileProps) -> HtmlResult {
+///     let id = props.id;
+///     let user = use_suspended_future(move |id| fetch_user(*id), id)?;
+///     Ok(match &*user {
+///         Ok(name) => html! { { name } },
+///         Err(err) => html! { { err } },
+///     })
+/// }
+/// # #[derive(Properties, PartialEq)] struct ProfileProps { id: u32 }
+/// # async fn fetch_user(_: u32) -> Result<String, String> { Ok(String::new()) }
+/// ```
+#[hook]
+pub fn use_suspended_future<F, Fut, T, E, Deps>(
+    future_factory: F,
+    deps: Deps,
+) -> SuspensionResult<UseSuspendedFutureHandle<T, E>>
+where
+    F: FnOnce(&Deps) -> Fut,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    T: 'static,
+    E: 'static,
+    Deps: PartialEq + 'static,
+{
+    // Holds the resolved value, tagged with the run that produced it. Reset to
+    // `None` each time `deps` change, and a value from an earlier run is never
+    // handed back, so the component re-suspends instead of returning a stale
+    // result.
+    let output = use_state(|| None);
+    // Identifies the latest run so a superseded future cannot overwrite `output`.
+    let generation = use_mut_ref(|| 0u32);
+    let deps = Rc::new(deps);
+
+    let run = {
+        let output = output.clone();
+        use_memo(
+            move |deps: &Rc<Deps>| {
+                output.set(None);
+                let this_gen = {
+                    let mut gen = generation.borrow_mut();
+                    *gen += 1;
+                    *gen
+                };
+                let (suspension, handle) = Suspension::new();
+                let future = future_factory(deps.as_ref());
+                spawn_local(async move {
+                    let result = future.await;
+                    if *generation.borrow() == this_gen {
+                        output.set(Some((this_gen, Rc::new(result))));
+                    }
+                    handle.resume();
+                });
+                (this_gen, suspension)
+            },
+            deps.clone(),
+        )
+    };
+
+    match &*output {
+        Some((gen, inner)) if *gen == run.0 => Ok(UseSuspendedFutureHandle {
+            inner: inner.clone(),
+        }),
+        _ => Err(run.1.clone()),
+    }
+}
@@ -0,0 +1,61 @@
+use std::cell::Cell;
+
+mod common;
+
+use common::obtain_result;
+use gloo::timers::future::TimeoutFuture;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+// A stale run must never overwrite a newer one: when `run()` is called twice and
+// the first future resolves after the second, only the second result survives.
+#[wasm_bindgen_test]
+async fn use_async_discards_stale_runs() {
+    thread_local! {
+        static RUNS: Cell<u32> = Cell::new(0);
+    }
+    RUNS.with(|r| r.set(0));
+
+    #[function_component(Runner)]
+    fn runner() -> Html {
+        let state = use_async(|| {
+            let run = RUNS.with(|r| {
+                let n = r.get() + 1;
+                r.set(n);
+                n
+            });
+            async move {
+                // The first run is slow, the second fast.
+                let delay = if run == 1 { 80 } else { 10 };
+                TimeoutFuture::new(delay).await;
+                Ok::<_, ()>(run)
+            }
+        });
+
+        use_effect_with_deps(
+            {
+                let state = state.clone();
+                move |_| {
+                    // Fire two runs back to back.
+                    state.run();
+                    state.run();
+                    || {}
+                }
+            },
+            (),
+        );
+
+        html! { <div id="result">{ state.data.unwrap_or(0) }</div> }
+    }
+
+    yew::start_app_in_element::<Runner>(
+        gloo_utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    TimeoutFuture::new(150).await;
+
+    // Second (newest) run wins; the slow first run is discarded.
+    assert_eq!(obtain_result().as_str(), "2");
+}
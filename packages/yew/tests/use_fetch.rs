@@ -0,0 +1,67 @@
+mod common;
+
+use common::obtain_result;
+use gloo::timers::future::TimeoutFuture;
+use wasm_bindgen_test::*;
+use web_sys::AbortSignal;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+// A slow earlier request must never overwrite a fast later one: once the dep
+// changes, the first request is aborted and only the latest result is written.
+#[wasm_bindgen_test]
+async fn use_fetch_last_writer_wins() {
+    #[derive(Properties, PartialEq)]
+    struct ViewerProps {
+        id: u32,
+    }
+
+    #[function_component(Viewer)]
+    fn viewer(props: &ViewerProps) -> Html {
+        let result = use_fetch(
+            |id: &u32, _signal: AbortSignal| {
+                let id = *id;
+                async move {
+                    // The first (id = 1) request is slow; the second is fast.
+                    let delay = if id == 1 { 80 } else { 10 };
+                    TimeoutFuture::new(delay).await;
+                    Ok::<_, ()>(id)
+                }
+            },
+            props.id,
+        );
+
+        html! { <div id="result">{ result.data().copied().unwrap_or(0) }</div> }
+    }
+
+    #[function_component(App)]
+    fn app() -> Html {
+        let id = use_state(|| 1u32);
+
+        use_effect_with_deps(
+            {
+                let id = id.clone();
+                move |_| {
+                    if *id < 2 {
+                        id.set(*id + 1);
+                    }
+                    || {}
+                }
+            },
+            *id,
+        );
+
+        html! { <Viewer id={*id} /> }
+    }
+
+    yew::start_app_in_element::<App>(
+        gloo_utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    // Wait past both the fast and the slow request.
+    TimeoutFuture::new(150).await;
+
+    // Only the latest (id = 2) result survives; the aborted slow one is dropped.
+    assert_eq!(obtain_result().as_str(), "2");
+}
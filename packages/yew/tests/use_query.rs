@@ -0,0 +1,63 @@
+use std::cell::Cell;
+
+mod common;
+
+use common::obtain_result;
+use gloo::timers::future::TimeoutFuture;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static FETCHES: Cell<u32> = Cell::new(0);
+}
+
+async fn fetch_value() -> Result<u32, ()> {
+    let n = FETCHES.with(|f| {
+        let n = f.get() + 1;
+        f.set(n);
+        n
+    });
+    Ok(n)
+}
+
+// Two components sharing a key trigger a single fetch (dedup), and `mutate`
+// forces a refetch that propagates to the mounted component.
+#[wasm_bindgen_test]
+async fn use_query_dedups_and_mutate_refetches() {
+    FETCHES.with(|f| f.set(0));
+
+    #[function_component(Viewer)]
+    fn viewer() -> Html {
+        let query = use_query("shared", || fetch_value());
+        html! { <div id="result">{ query.data().copied().unwrap_or(0) }</div> }
+    }
+
+    #[function_component(App)]
+    fn app() -> Html {
+        html! {
+            <>
+                <Viewer />
+                <Viewer />
+            </>
+        }
+    }
+
+    yew::start_app_in_element::<App>(
+        gloo_utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    TimeoutFuture::new(50).await;
+
+    // Both mounts deduped into a single fetch.
+    assert_eq!(FETCHES.with(|f| f.get()), 1);
+    assert_eq!(obtain_result().as_str(), "1");
+
+    // Invalidate the key: a fresh fetch runs and the result propagates.
+    yew::functional::mutate::<_, u32, ()>("shared");
+    TimeoutFuture::new(50).await;
+
+    assert_eq!(FETCHES.with(|f| f.get()), 2);
+    assert_eq!(obtain_result().as_str(), "2");
+}
@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod common;
+
+use common::obtain_result;
+use gloo::timers::future::TimeoutFuture;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+// The future is resolved once per distinct `deps` value; a re-render with an
+// unchanged dep must reuse the cached result instead of suspending again, while
+// a changed dep must re-suspend and re-resolve.
+#[wasm_bindgen_test]
+async fn use_suspended_future_caches_and_re_suspends_on_deps() {
+    thread_local! {
+        static RESOLVED: RefCell<Vec<u32>> = RefCell::new(Vec::new());
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct ChildProps {
+        id: u32,
+    }
+
+    #[function_component(Child)]
+    fn child(props: &ChildProps) -> HtmlResult {
+        let value = use_suspended_future(
+            |id: &u32| {
+                let id = *id;
+                async move {
+                    RESOLVED.with(|r| r.borrow_mut().push(id));
+                    Ok::<_, ()>(id * 2)
+                }
+            },
+            props.id,
+        )?;
+
+        Ok(html! { <div id="result">{ (*value).unwrap() }</div> })
+    }
+
+    #[function_component(App)]
+    fn app() -> Html {
+        let id = use_state(|| 1u32);
+
+        use_effect_with_deps(
+            {
+                let id = id.clone();
+                move |_| {
+                    if *id < 2 {
+                        id.set(*id + 1);
+                    }
+                    || {}
+                }
+            },
+            *id,
+        );
+
+        let fallback = html! { <div id="result">{ "loading" }</div> };
+        html! {
+            <Suspense {fallback}>
+                <Child id={*id} />
+            </Suspense>
+        }
+    }
+
+    yew::start_app_in_element::<App>(
+        gloo_utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    // Let both the initial future and the one for the changed dep resolve.
+    TimeoutFuture::new(50).await;
+
+    assert_eq!(obtain_result().as_str(), "4");
+    // Resolved exactly once per distinct dep (1 then 2), never re-resolving 2.
+    RESOLVED.with(|r| assert_eq!(*r.borrow(), vec![1, 2]));
+}